@@ -1,5 +1,12 @@
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use typed_absy::types::ConcreteSignature;
+use typed_absy::types::ConcreteStructType;
 use typed_absy::types::ConcreteType;
+use typed_absy::types::UBitwidth;
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
 pub struct AbiInput {
@@ -11,6 +18,26 @@ pub struct AbiInput {
 
 pub type AbiOutput = ConcreteType;
 
+/// The width, in bytes, of a field element's canonical little-endian encoding used by the SCALE
+/// codec. Fixed across fields in use in this crate (e.g. `Bn128Field`'s scalar field fits 32 bytes).
+const FIELD_ELEMENT_WIDTH: usize = 32;
+
+/// The width, in bytes, of a `ConcreteType::Uint`'s little-endian SCALE encoding.
+fn uint_byte_width(bitwidth: &UBitwidth) -> usize {
+    bitwidth.to_usize() / 8
+}
+
+/// A typed value bound to a `ConcreteType`, used as the in-memory representation shared by the
+/// JSON and SCALE (de)serialization paths.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Field(Vec<u8>),
+    Boolean(bool),
+    Uint(Vec<u8>),
+    Array(Vec<Value>),
+    Struct(Vec<Value>),
+}
+
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
 pub struct Abi {
     pub inputs: Vec<AbiInput>,
@@ -24,6 +51,609 @@ impl Abi {
             outputs: self.outputs.clone(),
         }
     }
+
+    /// Export this `Abi` as an Ethereum contract ABI function entry, in the format expected by
+    /// `ethabi`/Solidity tooling, so a generated verifier can be wrapped in a typed interface.
+    /// Only `public` inputs are included, as those are the ones the verifier actually exposes.
+    pub fn to_ethabi(&self) -> serde_json::Value {
+        let inputs: Vec<_> = self
+            .inputs
+            .iter()
+            .filter(|i| i.public)
+            .map(|i| ethabi_param(&i.ty, &i.name))
+            .collect();
+
+        let outputs: Vec<_> = self
+            .outputs
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| ethabi_param(ty, &format!("output_{}", i)))
+            .collect();
+
+        json!({
+            "name": "main",
+            "type": "function",
+            "stateMutability": "view",
+            "inputs": inputs,
+            "outputs": outputs,
+        })
+    }
+
+    /// Serialize this `Abi` to canonical JSON (cjson): object keys sorted lexicographically and
+    /// no insignificant whitespace, so the byte output is reproducible across platforms and serde
+    /// implementations, regardless of `HashMap`/`#[serde(flatten)]`-induced key ordering.
+    pub fn to_canonical_json(&self) -> String {
+        let value = serde_json::to_value(self).expect("Abi is always serializable");
+        canonicalize(&value)
+    }
+
+    /// A SHA-256 fingerprint of [`Abi::to_canonical_json`], used to pin a witness or proof to the
+    /// exact program ABI it was produced against.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(self.to_canonical_json().as_bytes());
+        hasher.finalize().into()
+    }
+
+    /// Bind a JSON document of arguments to this `Abi`'s inputs, accepting either a positional
+    /// array (current behavior) or an object keyed by `AbiInput::name` (mirroring JSON-RPC's
+    /// dual positional/named parameter structures), and type-checking each value along the way.
+    pub fn decode_arguments(&self, json: &str) -> Result<Vec<Value>, ArgumentsError> {
+        let mut de = serde_json::Deserializer::from_str(json);
+        ArgumentsSeed { abi: self }
+            .deserialize(&mut de)
+            .map_err(ArgumentsError::Json)
+    }
+}
+
+/// An error produced while binding a JSON document of arguments to an `Abi`.
+#[derive(Debug)]
+pub enum ArgumentsError {
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ArgumentsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgumentsError::Json(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ArgumentsError {}
+
+struct ArgumentsSeed<'a> {
+    abi: &'a Abi,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for ArgumentsSeed<'a> {
+    type Value = Vec<Value>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ArgumentsVisitor { abi: self.abi })
+    }
+}
+
+struct ArgumentsVisitor<'a> {
+    abi: &'a Abi,
+}
+
+impl<'de, 'a> Visitor<'de> for ArgumentsVisitor<'a> {
+    type Value = Vec<Value>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a JSON array of positional arguments or an object of named arguments"
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(self.abi.inputs.len());
+        for input in &self.abi.inputs {
+            let json: serde_json::Value = seq.next_element()?.ok_or_else(|| {
+                de::Error::custom(format!("missing argument for `{}`", input.name))
+            })?;
+            values.push(bind_value(&input.ty, json).map_err(de::Error::custom)?);
+        }
+        if seq.next_element::<serde_json::Value>()?.is_some() {
+            return Err(de::Error::custom("too many positional arguments"));
+        }
+        Ok(values)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut provided = HashMap::new();
+        while let Some((key, value)) = map.next_entry::<String, serde_json::Value>()? {
+            if provided.insert(key.clone(), value).is_some() {
+                return Err(de::Error::custom(format!("duplicate argument `{}`", key)));
+            }
+        }
+
+        let mut values = Vec::with_capacity(self.abi.inputs.len());
+        for input in &self.abi.inputs {
+            let json = provided
+                .remove(&input.name)
+                .ok_or_else(|| de::Error::custom(format!("missing argument `{}`", input.name)))?;
+            values.push(bind_value(&input.ty, json).map_err(de::Error::custom)?);
+        }
+
+        if let Some(unknown) = provided.keys().next() {
+            return Err(de::Error::custom(format!("unknown argument `{}`", unknown)));
+        }
+        Ok(values)
+    }
+}
+
+/// Type-check a JSON value against a `ConcreteType` and convert it to the typed `Value` tree,
+/// reusing the same representation the SCALE path consumes.
+fn bind_value(ty: &ConcreteType, json: serde_json::Value) -> Result<Value, String> {
+    match (ty, json) {
+        (ConcreteType::Boolean, serde_json::Value::Bool(b)) => Ok(Value::Boolean(b)),
+        (ConcreteType::FieldElement, serde_json::Value::String(s)) => {
+            decode_field_element(&s).map(Value::Field)
+        }
+        (ConcreteType::Uint(bitwidth), serde_json::Value::String(s)) => {
+            decode_unsigned_decimal(&s, uint_byte_width(bitwidth), "unsigned integer")
+                .map(Value::Uint)
+        }
+        (ConcreteType::Array(array_type), serde_json::Value::Array(elements)) => {
+            if elements.len() != array_type.size {
+                return Err(format!(
+                    "expected {} elements, found {}",
+                    array_type.size,
+                    elements.len()
+                ));
+            }
+            elements
+                .into_iter()
+                .map(|e| bind_value(&array_type.ty, e))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array)
+        }
+        (ConcreteType::Struct(struct_type), serde_json::Value::Object(mut fields)) => {
+            let members = struct_type
+                .members
+                .iter()
+                .map(|m| {
+                    let value = fields
+                        .remove(&m.id)
+                        .ok_or_else(|| format!("missing field `{}`", m.id))?;
+                    bind_value(&m.ty, value)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::Struct(members))
+        }
+        (ty, json) => Err(format!(
+            "expected a value of type `{:?}`, found `{}`",
+            ty, json
+        )),
+    }
+}
+
+/// The order of the `Bn128Field` scalar field, little-endian, i.e. the exclusive upper bound on
+/// any valid field element. Matches `FIELD_ELEMENT_WIDTH`.
+const FIELD_MODULUS_LE: [u8; FIELD_ELEMENT_WIDTH] = [
+    0x01, 0x00, 0x00, 0xf0, 0x93, 0xf5, 0xe1, 0x43, 0x91, 0x70, 0xb9, 0x79, 0x48, 0xe8, 0x33, 0x28,
+    0x5d, 0x58, 0x81, 0x81, 0xb6, 0x45, 0x50, 0xb8, 0x29, 0xa0, 0x31, 0xe1, 0x72, 0x4e, 0x64, 0x30,
+];
+
+/// Parse a decimal string into a little-endian byte representation of exactly `width` bytes,
+/// the JSON encoding shared by `FieldElement` and `Uint` values in the witness format. Errors if
+/// `s` is not a decimal number or does not fit in `width` bytes.
+fn decode_unsigned_decimal(s: &str, width: usize, label: &str) -> Result<Vec<u8>, String> {
+    if s.is_empty() {
+        return Err(format!("invalid {} `{}`", label, s));
+    }
+    let mut bytes = vec![0u8; width];
+    for c in s.chars() {
+        let digit = c
+            .to_digit(10)
+            .ok_or_else(|| format!("invalid {} `{}`", label, s))?;
+        let mut carry = digit;
+        for byte in bytes.iter_mut() {
+            let value = *byte as u32 * 10 + carry;
+            *byte = (value & 0xff) as u8;
+            carry = value >> 8;
+        }
+        if carry != 0 {
+            return Err(format!("{} `{}` exceeds {} bytes", label, s, width));
+        }
+    }
+    Ok(bytes)
+}
+
+/// Parse a decimal string into a field element's canonical little-endian byte representation,
+/// rejecting values that do not fit in `FIELD_ELEMENT_WIDTH` bytes or that are not strictly below
+/// the field modulus (and so could never be produced by a real field element).
+fn decode_field_element(s: &str) -> Result<Vec<u8>, String> {
+    let bytes = decode_unsigned_decimal(s, FIELD_ELEMENT_WIDTH, "field element")?;
+    if !is_below_field_modulus(&bytes) {
+        return Err(format!(
+            "field element `{}` is not less than the field modulus",
+            s
+        ));
+    }
+    Ok(bytes)
+}
+
+/// Compare a little-endian byte array against `FIELD_MODULUS_LE`, most significant byte first.
+fn is_below_field_modulus(bytes: &[u8]) -> bool {
+    for i in (0..FIELD_ELEMENT_WIDTH).rev() {
+        if bytes[i] != FIELD_MODULUS_LE[i] {
+            return bytes[i] < FIELD_MODULUS_LE[i];
+        }
+    }
+    false
+}
+
+/// Render a `serde_json::Value` as canonical JSON: objects have their keys sorted
+/// lexicographically and no whitespace separates any token.
+fn canonicalize(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let entries: Vec<String> = keys
+                .into_iter()
+                .map(|k| {
+                    format!(
+                        "{}:{}",
+                        serde_json::to_string(k).expect("String is always serializable"),
+                        canonicalize(&map[k])
+                    )
+                })
+                .collect();
+            format!("{{{}}}", entries.join(","))
+        }
+        serde_json::Value::Array(elements) => {
+            let entries: Vec<String> = elements.iter().map(canonicalize).collect();
+            format!("[{}]", entries.join(","))
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Map a `ConcreteType` to an ethabi-style parameter entry: `{"name": ..., "type": ...}`, plus a
+/// `components` array when the (possibly array-wrapped) base type is a struct (Solidity `tuple`).
+fn ethabi_param(ty: &ConcreteType, name: &str) -> serde_json::Value {
+    let mut base = ty;
+    let mut dims = vec![];
+    while let ConcreteType::Array(array_type) = base {
+        dims.push(array_type.size);
+        base = &*array_type.ty;
+    }
+    // innermost dimension is written first in the Solidity type string, e.g. `uint256[2][3]`
+    // for an outer array of 3 holding arrays of 2.
+    let suffix: String = dims
+        .iter()
+        .rev()
+        .map(|size| format!("[{}]", size))
+        .collect();
+
+    match base {
+        ConcreteType::FieldElement => json!({
+            "name": name,
+            "type": format!("uint256{}", suffix),
+        }),
+        ConcreteType::Boolean => json!({
+            "name": name,
+            "type": format!("bool{}", suffix),
+        }),
+        ConcreteType::Uint(bitwidth) => json!({
+            "name": name,
+            "type": format!("uint{}{}", bitwidth.to_usize(), suffix),
+        }),
+        ConcreteType::Struct(struct_type) => json!({
+            "name": name,
+            "type": format!("tuple{}", suffix),
+            "components": struct_type
+                .members
+                .iter()
+                .map(|m| ethabi_param(&m.ty, &m.id))
+                .collect::<Vec<_>>(),
+        }),
+    }
+}
+
+/// An error produced while decoding a SCALE-encoded byte string against an `Abi`'s signature.
+#[derive(Debug, PartialEq)]
+pub enum ScaleDecodeError {
+    UnexpectedEof,
+    TrailingBytes,
+    InvalidBoolean(u8),
+    FieldElementOutOfRange,
+}
+
+impl fmt::Display for ScaleDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaleDecodeError::UnexpectedEof => write!(f, "unexpected end of input"),
+            ScaleDecodeError::TrailingBytes => write!(f, "trailing bytes after decoding"),
+            ScaleDecodeError::InvalidBoolean(b) => write!(f, "invalid SCALE boolean byte `{}`", b),
+            ScaleDecodeError::FieldElementOutOfRange => {
+                write!(f, "field element is not less than the field modulus")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaleDecodeError {}
+
+/// Encode `values` as SCALE, walking `abi.signature().inputs` to drive the encoding of each value:
+/// no length prefixes are written for arrays or structs since their size is already known from
+/// the ABI. Panics if `values` does not match the ABI's inputs in arity or type.
+pub fn encode_scale(abi: &Abi, values: &[Value]) -> Vec<u8> {
+    let signature = abi.signature();
+    assert_eq!(
+        signature.inputs.len(),
+        values.len(),
+        "expected {} values, found {}",
+        signature.inputs.len(),
+        values.len()
+    );
+
+    let mut bytes = Vec::new();
+    for (ty, value) in signature.inputs.iter().zip(values) {
+        encode_value(ty, value, &mut bytes);
+    }
+    bytes
+}
+
+fn encode_value(ty: &ConcreteType, value: &Value, bytes: &mut Vec<u8>) {
+    match (ty, value) {
+        (ConcreteType::Boolean, Value::Boolean(b)) => bytes.push(*b as u8),
+        (ConcreteType::FieldElement, Value::Field(field)) => {
+            assert_eq!(field.len(), FIELD_ELEMENT_WIDTH, "malformed field element");
+            bytes.extend_from_slice(field);
+        }
+        (ConcreteType::Uint(bitwidth), Value::Uint(uint)) => {
+            assert_eq!(
+                uint.len(),
+                uint_byte_width(bitwidth),
+                "malformed u{} value",
+                bitwidth.to_usize()
+            );
+            bytes.extend_from_slice(uint);
+        }
+        (ConcreteType::Array(array_type), Value::Array(elements)) => {
+            assert_eq!(array_type.size, elements.len(), "array size mismatch");
+            for element in elements {
+                encode_value(&array_type.ty, element, bytes);
+            }
+        }
+        (ConcreteType::Struct(struct_type), Value::Struct(members)) => {
+            assert_eq!(
+                struct_type.members.len(),
+                members.len(),
+                "struct arity mismatch"
+            );
+            for (member, value) in struct_type.members.iter().zip(members) {
+                encode_value(&member.ty, value, bytes);
+            }
+        }
+        (ty, value) => panic!("value `{:?}` does not match type `{:?}`", value, ty),
+    }
+}
+
+/// Decode SCALE-encoded `bytes` into the typed value tree for each of `abi`'s inputs, using the
+/// ABI as the authoritative schema. Errors on truncated input or unconsumed trailing bytes.
+pub fn decode_scale(abi: &Abi, bytes: &[u8]) -> Result<Vec<Value>, ScaleDecodeError> {
+    let signature = abi.signature();
+    let mut cursor = 0;
+    let values = signature
+        .inputs
+        .iter()
+        .map(|ty| decode_value(ty, bytes, &mut cursor))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if cursor != bytes.len() {
+        return Err(ScaleDecodeError::TrailingBytes);
+    }
+    Ok(values)
+}
+
+fn decode_value(
+    ty: &ConcreteType,
+    bytes: &[u8],
+    cursor: &mut usize,
+) -> Result<Value, ScaleDecodeError> {
+    match ty {
+        ConcreteType::Boolean => {
+            let byte = *bytes.get(*cursor).ok_or(ScaleDecodeError::UnexpectedEof)?;
+            *cursor += 1;
+            match byte {
+                0 => Ok(Value::Boolean(false)),
+                1 => Ok(Value::Boolean(true)),
+                b => Err(ScaleDecodeError::InvalidBoolean(b)),
+            }
+        }
+        ConcreteType::FieldElement => {
+            let end = *cursor + FIELD_ELEMENT_WIDTH;
+            let field = bytes
+                .get(*cursor..end)
+                .ok_or(ScaleDecodeError::UnexpectedEof)?
+                .to_vec();
+            // mirrors `decode_field_element`'s modulus check, so the SCALE and JSON paths agree
+            // on what counts as a valid field element.
+            if !is_below_field_modulus(&field) {
+                return Err(ScaleDecodeError::FieldElementOutOfRange);
+            }
+            *cursor = end;
+            Ok(Value::Field(field))
+        }
+        ConcreteType::Uint(bitwidth) => {
+            let end = *cursor + uint_byte_width(bitwidth);
+            let uint = bytes
+                .get(*cursor..end)
+                .ok_or(ScaleDecodeError::UnexpectedEof)?
+                .to_vec();
+            *cursor = end;
+            Ok(Value::Uint(uint))
+        }
+        ConcreteType::Array(array_type) => {
+            let elements = (0..array_type.size)
+                .map(|_| decode_value(&array_type.ty, bytes, cursor))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::Array(elements))
+        }
+        ConcreteType::Struct(struct_type) => {
+            let members = struct_type
+                .members
+                .iter()
+                .map(|m| decode_value(&m.ty, bytes, cursor))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::Struct(members))
+        }
+    }
+}
+
+/// Generate Rust type bindings for `abi`: a `struct` for every distinct `ConcreteStructType`
+/// reachable from the inputs/outputs, plus top-level `Input`/`Output` structs matching the
+/// program's named inputs and output arity. A generated type is generic over the field type `T`
+/// only when one of its fields reaches a `FieldElement`; all of them derive
+/// `Serialize`/`Deserialize`/`Clone`/`Debug`/`PartialEq`, mirroring how Ethereum tooling derives
+/// typed bindings from a contract ABI.
+pub fn generate_bindings(abi: &Abi) -> String {
+    let mut seen = HashSet::new();
+    let mut struct_types = Vec::new();
+    for input in &abi.inputs {
+        collect_struct_types(&input.ty, &mut seen, &mut struct_types);
+    }
+    for output in &abi.outputs {
+        collect_struct_types(output, &mut seen, &mut struct_types);
+    }
+
+    let mut source = String::new();
+    for struct_type in &struct_types {
+        source.push_str(&render_struct(struct_type));
+        source.push('\n');
+    }
+    source.push_str(&render_fields_struct(
+        "Input",
+        abi.inputs.iter().map(|i| (i.name.clone(), &i.ty)),
+    ));
+    source.push('\n');
+    source.push_str(&render_fields_struct(
+        "Output",
+        abi.outputs
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| (format!("output_{}", i), ty)),
+    ));
+    source
+}
+
+/// Depth-first walk of `ty`, appending every distinct `ConcreteStructType` found (dependencies
+/// first) and deduplicating by `(module, name)` identity so each is emitted once.
+fn collect_struct_types(
+    ty: &ConcreteType,
+    seen: &mut HashSet<(String, String)>,
+    out: &mut Vec<ConcreteStructType>,
+) {
+    match ty {
+        ConcreteType::Struct(struct_type) => {
+            for member in &struct_type.members {
+                collect_struct_types(&member.ty, seen, out);
+            }
+            let key = (struct_type.module.clone(), struct_type.name.clone());
+            if seen.insert(key) {
+                out.push(struct_type.clone());
+            }
+        }
+        ConcreteType::Array(array_type) => collect_struct_types(&array_type.ty, seen, out),
+        _ => {}
+    }
+}
+
+/// Whether `ty` contains a `FieldElement` anywhere in its tree, and so needs the generic field
+/// type `T` to render. A struct that does not reach a `FieldElement` is rendered without `<T>`.
+fn type_uses_field(ty: &ConcreteType) -> bool {
+    match ty {
+        ConcreteType::FieldElement => true,
+        ConcreteType::Boolean => false,
+        ConcreteType::Uint(_) => false,
+        ConcreteType::Array(array_type) => type_uses_field(&array_type.ty),
+        ConcreteType::Struct(struct_type) => {
+            struct_type.members.iter().any(|m| type_uses_field(&m.ty))
+        }
+    }
+}
+
+/// The Rust identifier used for a generated struct binding. Two ZoKrates modules may declare a
+/// struct with the same `name`; `collect_struct_types` already keeps them as distinct entries (it
+/// dedups on `(module, name)`), so the rendered identifier must also disambiguate on `module`,
+/// or the generated source would declare the same Rust type twice.
+fn struct_binding_name(struct_type: &ConcreteStructType) -> String {
+    if struct_type.module.is_empty() {
+        struct_type.name.clone()
+    } else {
+        let module: String = struct_type
+            .module
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        format!("{}_{}", module, struct_type.name)
+    }
+}
+
+/// Render a `ConcreteType` as the Rust type used by a generated binding field. A referenced
+/// struct only takes a `<T>` argument when it actually uses the field type, mirroring how its
+/// own declaration is rendered by [`render_fields_struct`].
+fn render_binding_type(ty: &ConcreteType) -> String {
+    match ty {
+        ConcreteType::FieldElement => "T".to_string(),
+        ConcreteType::Boolean => "bool".to_string(),
+        ConcreteType::Uint(bitwidth) => format!("u{}", bitwidth.to_usize()),
+        ConcreteType::Array(array_type) => format!(
+            "[{}; {}]",
+            render_binding_type(&array_type.ty),
+            array_type.size
+        ),
+        ConcreteType::Struct(struct_type) => {
+            if type_uses_field(ty) {
+                format!("{}<T>", struct_binding_name(struct_type))
+            } else {
+                struct_binding_name(struct_type)
+            }
+        }
+    }
+}
+
+fn render_struct(struct_type: &ConcreteStructType) -> String {
+    render_fields_struct(
+        &struct_binding_name(struct_type),
+        struct_type.members.iter().map(|m| (m.id.clone(), &m.ty)),
+    )
+}
+
+fn render_fields_struct<'a>(
+    name: &str,
+    fields: impl Iterator<Item = (String, &'a ConcreteType)>,
+) -> String {
+    let fields: Vec<(String, &ConcreteType)> = fields.collect();
+    let generic = if fields.iter().any(|(_, ty)| type_uses_field(ty)) {
+        "<T>"
+    } else {
+        ""
+    };
+    let body: String = fields
+        .iter()
+        .map(|(name, ty)| format!("    pub {}: {},\n", name, render_binding_type(ty)))
+        .collect();
+    format!(
+        "#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]\npub struct {}{} {{\n{}}}\n",
+        name, generic, body
+    )
 }
 
 #[cfg(test)]
@@ -389,4 +1019,498 @@ mod tests {
 }"#
         )
     }
+
+    #[test]
+    fn ethabi_only_public_inputs() {
+        let abi: Abi = Abi {
+            inputs: vec![
+                AbiInput {
+                    name: String::from("a"),
+                    public: false,
+                    ty: ConcreteType::FieldElement,
+                },
+                AbiInput {
+                    name: String::from("b"),
+                    public: true,
+                    ty: ConcreteType::Boolean,
+                },
+            ],
+            outputs: vec![ConcreteType::FieldElement],
+        };
+
+        assert_eq!(
+            abi.to_ethabi(),
+            serde_json::json!({
+                "name": "main",
+                "type": "function",
+                "stateMutability": "view",
+                "inputs": [
+                    {"name": "b", "type": "bool"}
+                ],
+                "outputs": [
+                    {"name": "output_0", "type": "uint256"}
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn ethabi_nested_array() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::Array(ConcreteArrayType::new(
+                    ConcreteType::Array(ConcreteArrayType::new(ConcreteType::FieldElement, 2)),
+                    2,
+                )),
+            }],
+            outputs: vec![],
+        };
+
+        assert_eq!(
+            abi.to_ethabi()["inputs"],
+            serde_json::json!([{"name": "a", "type": "uint256[2][2]"}])
+        );
+    }
+
+    #[test]
+    fn ethabi_struct() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("foo"),
+                public: true,
+                ty: ConcreteType::Struct(ConcreteStructType::new(
+                    "".into(),
+                    "Foo".into(),
+                    vec![
+                        ConcreteStructMember::new(String::from("a"), ConcreteType::FieldElement),
+                        ConcreteStructMember::new(String::from("b"), ConcreteType::Boolean),
+                    ],
+                )),
+            }],
+            outputs: vec![],
+        };
+
+        assert_eq!(
+            abi.to_ethabi()["inputs"],
+            serde_json::json!([{
+                "name": "foo",
+                "type": "tuple",
+                "components": [
+                    {"name": "a", "type": "uint256"},
+                    {"name": "b", "type": "bool"}
+                ],
+            }])
+        );
+    }
+
+    #[test]
+    fn ethabi_uint() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::Uint(UBitwidth::B32),
+            }],
+            outputs: vec![],
+        };
+
+        assert_eq!(
+            abi.to_ethabi()["inputs"],
+            serde_json::json!([{"name": "a", "type": "uint32"}])
+        );
+    }
+
+    #[test]
+    fn canonical_json_sorts_keys() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::FieldElement,
+            }],
+            outputs: vec![ConcreteType::FieldElement],
+        };
+
+        assert_eq!(
+            abi.to_canonical_json(),
+            r#"{"inputs":[{"name":"a","public":true,"type":"field"}],"outputs":[{"type":"field"}]}"#
+        );
+    }
+
+    #[test]
+    fn fingerprint_is_deterministic_and_discriminating() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::FieldElement,
+            }],
+            outputs: vec![],
+        };
+
+        let other: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::Boolean,
+            }],
+            outputs: vec![],
+        };
+
+        assert_eq!(abi.fingerprint(), abi.fingerprint());
+        assert_ne!(abi.fingerprint(), other.fingerprint());
+    }
+
+    fn field(n: u8) -> Value {
+        let mut bytes = vec![0u8; FIELD_ELEMENT_WIDTH];
+        bytes[0] = n;
+        Value::Field(bytes)
+    }
+
+    #[test]
+    fn scale_roundtrip_field_and_bool() {
+        let abi: Abi = Abi {
+            inputs: vec![
+                AbiInput {
+                    name: String::from("a"),
+                    public: true,
+                    ty: ConcreteType::FieldElement,
+                },
+                AbiInput {
+                    name: String::from("b"),
+                    public: true,
+                    ty: ConcreteType::Boolean,
+                },
+            ],
+            outputs: vec![],
+        };
+
+        let values = vec![field(42), Value::Boolean(true)];
+        let encoded = encode_scale(&abi, &values);
+        assert_eq!(encoded.len(), FIELD_ELEMENT_WIDTH + 1);
+        assert_eq!(decode_scale(&abi, &encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn scale_array_has_no_length_prefix() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::Array(ConcreteArrayType::new(ConcreteType::Boolean, 3)),
+            }],
+            outputs: vec![],
+        };
+
+        let values = vec![Value::Array(vec![
+            Value::Boolean(true),
+            Value::Boolean(false),
+            Value::Boolean(true),
+        ])];
+        let encoded = encode_scale(&abi, &values);
+        assert_eq!(encoded, vec![1, 0, 1]);
+        assert_eq!(decode_scale(&abi, &encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn scale_roundtrip_uint() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::Uint(UBitwidth::B32),
+            }],
+            outputs: vec![],
+        };
+
+        let values = vec![Value::Uint(vec![1, 2, 3, 4])];
+        let encoded = encode_scale(&abi, &values);
+        assert_eq!(encoded, vec![1, 2, 3, 4]);
+        assert_eq!(decode_scale(&abi, &encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn scale_errors_on_trailing_bytes() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::Boolean,
+            }],
+            outputs: vec![],
+        };
+
+        assert_eq!(
+            decode_scale(&abi, &[1, 0]),
+            Err(ScaleDecodeError::TrailingBytes)
+        );
+    }
+
+    #[test]
+    fn scale_errors_on_truncated_input() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::FieldElement,
+            }],
+            outputs: vec![],
+        };
+
+        assert_eq!(
+            decode_scale(&abi, &[0u8; FIELD_ELEMENT_WIDTH - 1]),
+            Err(ScaleDecodeError::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn scale_errors_on_field_element_at_or_above_modulus() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::FieldElement,
+            }],
+            outputs: vec![],
+        };
+
+        assert_eq!(
+            decode_scale(&abi, &FIELD_MODULUS_LE),
+            Err(ScaleDecodeError::FieldElementOutOfRange)
+        );
+
+        let mut below_modulus = FIELD_MODULUS_LE;
+        below_modulus[0] -= 1;
+        assert!(decode_scale(&abi, &below_modulus).is_ok());
+    }
+
+    fn two_input_abi() -> Abi {
+        Abi {
+            inputs: vec![
+                AbiInput {
+                    name: String::from("a"),
+                    public: true,
+                    ty: ConcreteType::FieldElement,
+                },
+                AbiInput {
+                    name: String::from("b"),
+                    public: false,
+                    ty: ConcreteType::Boolean,
+                },
+            ],
+            outputs: vec![],
+        }
+    }
+
+    #[test]
+    fn decode_arguments_positional() {
+        let abi = two_input_abi();
+        let values = abi.decode_arguments(r#"["42", true]"#).unwrap();
+        assert_eq!(values, vec![field(42), Value::Boolean(true)]);
+    }
+
+    #[test]
+    fn decode_arguments_named() {
+        let abi = two_input_abi();
+        let values = abi.decode_arguments(r#"{"b": true, "a": "42"}"#).unwrap();
+        assert_eq!(values, vec![field(42), Value::Boolean(true)]);
+    }
+
+    #[test]
+    fn decode_arguments_positional_arity_mismatch() {
+        let abi = two_input_abi();
+        assert!(abi.decode_arguments(r#"["42"]"#).is_err());
+        assert!(abi.decode_arguments(r#"["42", true, false]"#).is_err());
+    }
+
+    #[test]
+    fn decode_arguments_named_unknown_key() {
+        let abi = two_input_abi();
+        assert!(abi
+            .decode_arguments(r#"{"a": "42", "b": true, "c": 1}"#)
+            .is_err());
+    }
+
+    #[test]
+    fn decode_arguments_named_missing_key() {
+        let abi = two_input_abi();
+        assert!(abi.decode_arguments(r#"{"a": "42"}"#).is_err());
+    }
+
+    #[test]
+    fn decode_arguments_uint() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::Uint(UBitwidth::B32),
+            }],
+            outputs: vec![],
+        };
+
+        assert_eq!(
+            abi.decode_arguments(r#"["42"]"#).unwrap(),
+            vec![Value::Uint(vec![42, 0, 0, 0])]
+        );
+        assert_eq!(
+            abi.decode_arguments(r#"{"a": "42"}"#).unwrap(),
+            vec![Value::Uint(vec![42, 0, 0, 0])]
+        );
+    }
+
+    #[test]
+    fn decode_arguments_rejects_field_element_at_or_above_modulus() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::FieldElement,
+            }],
+            outputs: vec![],
+        };
+
+        // the field modulus itself is out of range
+        assert!(abi
+            .decode_arguments(
+                r#"["21888242871839275222246405745257275088548364400416034343698204186575808495617"]"#
+            )
+            .is_err());
+        // modulus - 1 is the largest valid field element
+        assert!(abi
+            .decode_arguments(
+                r#"["21888242871839275222246405745257275088548364400416034343698204186575808495616"]"#
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn generate_bindings_scalar_input_output() {
+        let abi = two_input_abi();
+        let source = generate_bindings(&abi);
+
+        // `Input` uses `T` (field `a`); `Output` has no fields at all, so it gets no `<T>`.
+        assert_eq!(
+            source,
+            "#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]\npub struct Input<T> {\n    pub a: T,\n    pub b: bool,\n}\n\n#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]\npub struct Output {\n}\n"
+        );
+    }
+
+    #[test]
+    fn generate_bindings_emits_struct_once_with_correct_generic() {
+        let foo = ConcreteType::Struct(ConcreteStructType::new(
+            "".into(),
+            "Foo".into(),
+            vec![ConcreteStructMember::new(
+                String::from("a"),
+                ConcreteType::FieldElement,
+            )],
+        ));
+
+        let abi: Abi = Abi {
+            inputs: vec![
+                AbiInput {
+                    name: String::from("x"),
+                    public: true,
+                    ty: foo.clone(),
+                },
+                AbiInput {
+                    name: String::from("y"),
+                    public: true,
+                    ty: ConcreteType::Array(ConcreteArrayType::new(foo, 2)),
+                },
+            ],
+            outputs: vec![],
+        };
+
+        let source = generate_bindings(&abi);
+        assert_eq!(source.matches("pub struct Foo<T>").count(), 1);
+        assert!(source.contains("pub x: Foo<T>,"));
+        assert!(source.contains("pub y: [Foo<T>; 2],"));
+    }
+
+    #[test]
+    fn generate_bindings_struct_without_field_element_has_no_generic() {
+        let foo = ConcreteType::Struct(ConcreteStructType::new(
+            "".into(),
+            "Flags".into(),
+            vec![ConcreteStructMember::new(
+                String::from("a"),
+                ConcreteType::Boolean,
+            )],
+        ));
+
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("x"),
+                public: true,
+                ty: foo,
+            }],
+            outputs: vec![],
+        };
+
+        let source = generate_bindings(&abi);
+        assert!(source.contains("pub struct Flags {\n    pub a: bool,\n}"));
+        assert!(source.contains("pub struct Input {\n    pub x: Flags,\n}"));
+    }
+
+    #[test]
+    fn generate_bindings_uint() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: true,
+                ty: ConcreteType::Uint(UBitwidth::B32),
+            }],
+            outputs: vec![],
+        };
+
+        let source = generate_bindings(&abi);
+        assert!(source.contains("pub a: u32,"));
+    }
+
+    #[test]
+    fn generate_bindings_disambiguates_same_named_structs_across_modules() {
+        let foo_in_main = ConcreteType::Struct(ConcreteStructType::new(
+            "main".into(),
+            "Foo".into(),
+            vec![ConcreteStructMember::new(
+                String::from("a"),
+                ConcreteType::FieldElement,
+            )],
+        ));
+        let foo_in_other = ConcreteType::Struct(ConcreteStructType::new(
+            "other".into(),
+            "Foo".into(),
+            vec![ConcreteStructMember::new(
+                String::from("b"),
+                ConcreteType::Boolean,
+            )],
+        ));
+
+        let abi: Abi = Abi {
+            inputs: vec![
+                AbiInput {
+                    name: String::from("x"),
+                    public: true,
+                    ty: foo_in_main,
+                },
+                AbiInput {
+                    name: String::from("y"),
+                    public: true,
+                    ty: foo_in_other,
+                },
+            ],
+            outputs: vec![],
+        };
+
+        let source = generate_bindings(&abi);
+        assert!(source.contains("pub struct main_Foo<T>"));
+        assert!(source.contains("pub struct other_Foo {"));
+        assert!(source.contains("pub x: main_Foo<T>,"));
+        assert!(source.contains("pub y: other_Foo,"));
+        // the two distinct structs must not collide on a single `struct Foo` declaration
+        assert_eq!(source.matches("pub struct Foo").count(), 0);
+    }
 }